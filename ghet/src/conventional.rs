@@ -0,0 +1,51 @@
+//! The *conventional* module splits a commit message's subject line into the pieces of its
+//! [Conventional Commits] grammar (`type(scope)!: description`). Both the `ghet` binary's own
+//! auto-categorization and `release-maker`'s changelog generation route commits into sections
+//! from this same syntax, but map the pieces to their own section enums differently, so only
+//! the grammar itself lives here rather than being forked per binary.
+//!
+//! [Conventional Commits]: https://www.conventionalcommits.org/
+
+/// The pieces of a Conventional Commit subject line.
+pub struct Head<'a> {
+    /// The commit type, e.g. `feat` or `fix`.
+    pub ty: &'a str,
+    /// The optional scope between parentheses, e.g. `parser` in `fix(parser): ...`.
+    pub scope: Option<String>,
+    /// Whether the change is breaking: either the subject carries a `!` before the colon, or
+    /// the commit body has a `BREAKING CHANGE:` footer.
+    pub breaking: bool,
+    /// The description after the colon.
+    pub description: &'a str,
+}
+
+/// Splits `message`'s subject line into its Conventional Commit [`Head`], or `None` when the
+/// line has no `:` at all. Everything before the colon is taken as the type without checking
+/// it against the known Conventional Commit types; callers decide which types they recognise.
+///
+/// `message` is the full commit message, not just its subject line, so the `BREAKING CHANGE:`
+/// footer can be found in the body even when the subject itself has no `!` marker.
+pub fn parse_head(message: &str) -> Option<Head<'_>> {
+    let subject = message.lines().next().unwrap_or(message);
+    let breaking_footer = message.contains("BREAKING CHANGE:");
+
+    let colon = subject.find(':')?;
+    let (head, description) = (&subject[..colon], subject[colon + 1..].trim());
+    let breaking = breaking_footer || head.ends_with('!');
+    let head = head.trim_end_matches('!');
+
+    let (ty, scope) = match head.find('(') {
+        Some(paren) if head.ends_with(')') => (
+            &head[..paren],
+            Some(head[paren + 1..head.len() - 1].to_string()),
+        ),
+        _ => (head, None),
+    };
+
+    Some(Head {
+        ty,
+        scope,
+        breaking,
+        description,
+    })
+}
@@ -1,10 +1,12 @@
 //! The *git* module defines an abstraction to the necessary wheels and cogs for understanding and
 //! manipulating Git repositories. The wheels and cogs may be the `git` binary, or the `libgit2` C library.
 
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use git2::Repository as Git2Repository;
 
-use std::path::Path;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
 
 /// Defines a Git user.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -29,9 +31,7 @@ mod private {
 
     impl Restricted for Git2Repository {}
 
-    pub enum Void {}
-
-    impl Restricted for Void {}
+    impl Restricted for GitBinRepository {}
 }
 
 /// Specifies an abstraction to a repository by the `git` binary, or the `libgit2` C library.
@@ -75,7 +75,7 @@ impl Repository for Git2Repository {
                     name: committer.name().unwrap().to_string(),
                     email: committer.email().unwrap().to_string(),
                 },
-                message: commit.summary().unwrap().to_string(),
+                message: commit.message().unwrap().to_string(),
             });
         }
 
@@ -83,13 +83,104 @@ impl Repository for Git2Repository {
     }
 }
 
-impl Repository for private::Void {
+/// A separator placed between the fields of a single commit in the machine-parseable
+/// `git log` output.
+const FIELD_SEP: &str = "\x00";
+/// A separator placed between individual commits in the machine-parseable `git log` output.
+const RECORD_SEP: &str = "\x1e";
+
+/// A handle to a repository manipulated through the system `git` executable.
+#[derive(Debug, Clone)]
+pub struct GitBinRepository {
+    git_dir: PathBuf,
+}
+
+impl GitBinRepository {
+    /// A reusable set of global arguments prepended to every invocation of `git`, pinning it
+    /// to this repository regardless of the process' current directory.
+    fn global_args(&self) -> Vec<&OsStr> {
+        vec![OsStr::new("-C"), self.git_dir.as_os_str()]
+    }
+
+    /// Spawns `git` with `args` appended after the [global arguments], returning its captured
+    /// output, or an error if the process could not be spawned or exited unsuccessfully.
+    ///
+    /// [global arguments]: Self::global_args
+    fn run<I, S>(&self, args: I) -> Result<Output>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let output = Command::new("git")
+            .args(self.global_args())
+            .args(args)
+            .output()
+            .context("failed to spawn the `git` executable")?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "`git` exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(output)
+    }
+}
+
+impl Repository for GitBinRepository {
     fn url(&self) -> Result<String> {
-        unimplemented!()
+        let output = self.run(&["remote", "get-url", "origin"])?;
+
+        Ok(String::from_utf8(output.stdout)?.trim().to_string())
     }
 
-    fn commits(&self, _branch: &str) -> Result<Vec<Commit>> {
-        unimplemented!()
+    fn commits(&self, branch: &str) -> Result<Vec<Commit>> {
+        let pretty = format!(
+            "--pretty=format:%H{sep}%an{sep}%ae{sep}%cn{sep}%ce{sep}%B{rec}",
+            sep = FIELD_SEP,
+            rec = RECORD_SEP
+        );
+
+        let target = format!("refs/remotes/origin/{}", branch);
+        let output = self.run(&["log", &target, "--topo-order", &pretty])?;
+        let stdout = String::from_utf8(output.stdout)?;
+
+        stdout
+            .split(RECORD_SEP)
+            .map(str::trim)
+            .filter(|record| !record.is_empty())
+            .map(|record| {
+                let mut fields = record.split(FIELD_SEP);
+
+                let mut next_field = || {
+                    fields
+                        .next()
+                        .context("`git log` produced a record with a missing field")
+                };
+
+                let hash = next_field()?.to_string();
+                let author_name = next_field()?.to_string();
+                let author_email = next_field()?.to_string();
+                let committer_name = next_field()?.to_string();
+                let committer_email = next_field()?.to_string();
+                let message = next_field()?.trim().to_string();
+
+                Ok(Commit {
+                    hash,
+                    author: User {
+                        name: author_name,
+                        email: author_email,
+                    },
+                    committer: User {
+                        name: committer_name,
+                        email: committer_email,
+                    },
+                    message,
+                })
+            })
+            .collect()
     }
 }
 
@@ -132,26 +223,44 @@ impl Git for Git2 {
     }
 }
 
-/// Provides Git capabilities using the `git` binary.
+/// Provides Git capabilities by spawning the system `git` executable as a subprocess.
 ///
-/// UNIMPLEMENTED.
+/// This lets the crate run in environments where linking `libgit2` is undesirable, at the
+/// cost of depending on `git` being installed and reachable on `PATH`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GitBin;
 
 impl Git for GitBin {
-    type Repository = private::Void;
+    type Repository = GitBinRepository;
 
-    fn clone<P>(&self, _repo_url: &str, _destination: P) -> Result<Self::Repository>
+    fn clone<P>(&self, repo_url: &str, destination: P) -> Result<Self::Repository>
     where
         P: AsRef<Path>,
     {
-        todo!()
+        let destination = destination.as_ref();
+
+        let output = Command::new("git")
+            .args(&[OsStr::new("clone"), OsStr::new(repo_url), destination.as_os_str()])
+            .output()
+            .context("failed to spawn the `git` executable")?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "`git clone` exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        self.open(destination)
     }
 
-    fn open<P>(&self, _repo_path: P) -> Result<Self::Repository>
+    fn open<P>(&self, repo_path: P) -> Result<Self::Repository>
     where
         P: AsRef<Path>,
     {
-        todo!()
+        Ok(GitBinRepository {
+            git_dir: repo_path.as_ref().to_path_buf(),
+        })
     }
 }
@@ -0,0 +1,186 @@
+//! The *github* module enriches commits with data from the GitHub REST API: a commit author's
+//! GitHub login and display name, and the pull request (if any) that introduced the commit.
+
+use crate::User;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// The result of resolving a commit author: their GitHub login (or the raw Git `user.name`
+/// when unresolved) and, when a token is configured and the lookup succeeds, their GitHub
+/// display name.
+pub struct ResolvedAuthor {
+    pub login: String,
+    pub display_name: Option<String>,
+}
+
+/// Resolves a commit author to their GitHub login and display name, and a commit to the
+/// number of the pull request that introduced it, caching each lookup per run so the same
+/// author or commit isn't fetched twice.
+///
+/// Falls back to the raw commit author name, and to no pull request, when no token is
+/// configured or a lookup fails.
+pub struct GitHubResolver {
+    token: Option<String>,
+    agent: ureq::Agent,
+    authors: RefCell<HashMap<String, String>>,
+    display_names: RefCell<HashMap<String, String>>,
+    pulls: RefCell<HashMap<String, Option<u64>>>,
+}
+
+impl GitHubResolver {
+    /// Creates a resolver. Without a `token`, every lookup is skipped and [`resolve_author`]
+    /// and [`resolve_pull_request`] degrade to their offline fallback.
+    ///
+    /// [`resolve_author`]: Self::resolve_author
+    /// [`resolve_pull_request`]: Self::resolve_pull_request
+    pub fn new(token: Option<String>) -> Self {
+        Self {
+            token,
+            agent: ureq::Agent::new(),
+            authors: RefCell::new(HashMap::new()),
+            display_names: RefCell::new(HashMap::new()),
+            pulls: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Resolves the author of `sha` in `owner/repo`, falling back to `user.name` as the login
+    /// and no display name when no token is configured or the lookup fails.
+    pub fn resolve_author(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        user: &User,
+    ) -> ResolvedAuthor {
+        if let Some(login) = self.authors.borrow().get(&user.email) {
+            return ResolvedAuthor {
+                login: login.clone(),
+                display_name: self.display_names.borrow().get(login).cloned(),
+            };
+        }
+
+        let resolved = self
+            .token
+            .as_deref()
+            .and_then(|token| self.fetch_author(token, owner, repo, sha));
+
+        let (login, display_name) = match resolved {
+            Some((login, display_name)) => (login, Some(display_name)),
+            None => (user.name.clone(), None),
+        };
+
+        self.authors
+            .borrow_mut()
+            .insert(user.email.clone(), login.clone());
+
+        if let Some(display_name) = &display_name {
+            self.display_names
+                .borrow_mut()
+                .insert(login.clone(), display_name.clone());
+        }
+
+        ResolvedAuthor {
+            login,
+            display_name,
+        }
+    }
+
+    /// Resolves the number of the pull request that introduced `sha` in `owner/repo`, or
+    /// `None` when no token is configured, the lookup fails, or no pull request is associated
+    /// with the commit.
+    pub fn resolve_pull_request(&self, owner: &str, repo: &str, sha: &str) -> Option<u64> {
+        if let Some(number) = self.pulls.borrow().get(sha) {
+            return *number;
+        }
+
+        let number = self
+            .token
+            .as_deref()
+            .and_then(|token| self.fetch_pull_request(token, owner, repo, sha));
+
+        self.pulls.borrow_mut().insert(sha.to_string(), number);
+
+        number
+    }
+
+    fn fetch_author(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Option<(String, String)> {
+        let url = format!(
+            "https://api.github.com/repos/{}/{}/commits/{}",
+            owner, repo, sha
+        );
+
+        let commit: serde_json::Value = self
+            .agent
+            .get(&url)
+            .set("Authorization", &format!("token {}", token))
+            .set("User-Agent", "ghet")
+            .call()
+            .ok()?
+            .into_json()
+            .ok()?;
+
+        let login = commit["author"]["login"].as_str()?;
+
+        let url = format!("https://api.github.com/users/{}", login);
+
+        let user: serde_json::Value = self
+            .agent
+            .get(&url)
+            .set("Authorization", &format!("token {}", token))
+            .set("User-Agent", "ghet")
+            .call()
+            .ok()?
+            .into_json()
+            .ok()?;
+
+        let display_name = user["name"].as_str().unwrap_or(login);
+
+        Some((login.to_string(), display_name.to_string()))
+    }
+
+    fn fetch_pull_request(&self, token: &str, owner: &str, repo: &str, sha: &str) -> Option<u64> {
+        let url = format!(
+            "https://api.github.com/repos/{}/{}/commits/{}/pulls",
+            owner, repo, sha
+        );
+
+        let pulls: serde_json::Value = self
+            .agent
+            .get(&url)
+            .set("Authorization", &format!("token {}", token))
+            .set("User-Agent", "ghet")
+            .call()
+            .ok()?
+            .into_json()
+            .ok()?;
+
+        pulls.get(0)?["number"].as_u64()
+    }
+}
+
+/// Splits a GitHub repository URL into its owner and repository name, accepting both the
+/// `https://github.com/{owner}/{repo}` form and the `git@github.com:{owner}/{repo}` SSH form.
+pub fn owner_repo_from_url(repo_url: &str) -> Option<(String, String)> {
+    let trimmed = repo_url.trim_end_matches('/').trim_end_matches(".git");
+
+    // An SSH remote in `scp`-like form (`git@host:owner/repo`) separates the host from the
+    // path with a bare `:`, unlike an `https://host/owner/repo` URL's `://`. Strip that host
+    // prefix first, or `owner` would come out as `git@host`.
+    let path = match trimmed.find("://") {
+        Some(_) => trimmed,
+        None => trimmed.rsplit_once(':').map_or(trimmed, |(_, path)| path),
+    };
+
+    let mut parts = path.rsplit('/');
+    let repo = parts.next()?;
+    let owner = parts.next()?;
+
+    Some((owner.to_string(), repo.to_string()))
+}
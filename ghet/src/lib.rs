@@ -1,6 +1,9 @@
 //! The *ghet* crate defines a small abstraction of the libgit2 C library
 //! to simplify its usage for the `ghet` binary.
 
+pub mod conventional;
+pub mod github;
+
 use anyhow::Result;
 
 use std::path::Path;
@@ -49,7 +52,9 @@ impl Repository {
     ///
     /// [`Commit`]: struct.Commit.html
     pub fn commits(&self, branch: &str) -> Result<Vec<Commit>> {
-        let reference = self.inner.find_reference(&format!("refs/remotes/origin/{}", branch))?;
+        let reference = self
+            .inner
+            .find_reference(&format!("refs/remotes/origin/{}", branch))?;
 
         let mut revwalk = self.inner.revwalk()?;
         revwalk.push(reference.target().unwrap())?;
@@ -72,7 +77,7 @@ impl Repository {
                     name: committer.name().unwrap().to_string(),
                     email: committer.email().unwrap().to_string(),
                 },
-                message: commit.summary().unwrap().to_string(),
+                message: commit.message().unwrap().to_string(),
             });
         }
 
@@ -1,8 +1,10 @@
 use anyhow::Result;
+use clap::Clap;
+use ghet::conventional::parse_head;
+use ghet::github::{owner_repo_from_url, GitHubResolver};
 use ghet::{Commit, Repository};
 use rmaker::{Change, Release};
 use serde_json::to_string_pretty;
-use clap::Clap;
 
 /// Get a list of commits from a Git repository.
 #[derive(Clap)]
@@ -21,16 +23,105 @@ struct App {
     /// If left undefined, this will retrieve ALL commits from the start of the list.
     #[clap(short, long)]
     end: Option<String>,
+    /// A GitHub personal access token, used to resolve commit authors to their GitHub login
+    /// and display name, and to cross-link commits to the pull request that introduced them.
+    #[clap(long, env = "GITHUB_TOKEN", hide_env_values = true)]
+    token: Option<String>,
+}
+
+/// The section of the `Release` a commit's Conventional Commit `type` maps to.
+enum Section {
+    Added,
+    Changed,
+    Fixed,
+    Removed,
+}
+
+/// Routes a commit's [`Head`](ghet::conventional::Head) to the `Release` section it maps to,
+/// returning that section, the category (the scope, or the type when no scope is given) and
+/// the description.
+///
+/// A message with no recognisable `type:` prefix falls back to `Section::Added`, with `"any"`
+/// as the category and the whole subject as the description. A breaking change (see
+/// [`Head::breaking`](ghet::conventional::Head::breaking)) keeps its mapped section and instead
+/// gets a trailing `!` appended to its category, the same marker Conventional Commits itself
+/// uses, so breaking changes stand out without being misfiled as removed.
+fn parse_subject(message: &str) -> (Section, String, String) {
+    let fallback = || {
+        let subject = message.lines().next().unwrap_or(message);
+        (Section::Added, "any".to_string(), subject.to_string())
+    };
+
+    let head = match parse_head(message) {
+        Some(head) => head,
+        None => return fallback(),
+    };
+
+    let section = match (head.ty, head.scope.as_deref()) {
+        ("feat", _) => Section::Added,
+        ("fix", _) => Section::Fixed,
+        ("refactor", _) | ("perf", _) | ("style", _) => Section::Changed,
+        ("chore", Some("remove")) | ("revert", _) => Section::Removed,
+        _ => return fallback(),
+    };
+
+    let category = head.scope.unwrap_or_else(|| head.ty.to_string());
+    let category = if head.breaking {
+        format!("{}!", category)
+    } else {
+        category
+    };
+
+    (section, category, head.description.to_string())
 }
 
-fn generate_release(repo_url: String, commits: impl Iterator<Item = Commit>) -> Release {
-    Release {
+fn generate_release(
+    repo_url: String,
+    commits: impl Iterator<Item = Commit>,
+    resolver: &GitHubResolver,
+) -> Release {
+    let owner_repo = owner_repo_from_url(&repo_url);
+
+    let mut release = Release {
         repo_url,
-        added: commits
-            .map(|commit| Change::new("any", commit.message, commit.author.name, commit.hash))
-            .collect(),
         ..Default::default()
+    };
+
+    for commit in commits {
+        let (section, category, name) = parse_subject(&commit.message);
+
+        let login = match &owner_repo {
+            Some((owner, repo)) => {
+                let author = resolver.resolve_author(owner, repo, &commit.hash, &commit.author);
+
+                if let Some(display_name) = author.display_name {
+                    release
+                        .author_names
+                        .insert(author.login.clone(), display_name);
+                }
+
+                if let Some(pr) = resolver.resolve_pull_request(owner, repo, &commit.hash) {
+                    release.prs.insert(commit.hash.clone(), pr);
+                }
+
+                author.login
+            }
+            None => commit.author.name.clone(),
+        };
+
+        let change = Change::new(category, name, login, commit.hash);
+
+        let bucket = match section {
+            Section::Added => &mut release.added,
+            Section::Changed => &mut release.changed,
+            Section::Fixed => &mut release.fixed,
+            Section::Removed => &mut release.removed,
+        };
+
+        bucket.push(change);
     }
+
+    release
 }
 
 fn main() -> Result<()> {
@@ -47,7 +138,8 @@ fn main() -> Result<()> {
         commits = commits.end(&end);
     }
 
-    let release = generate_release(repo.url()?, commits);
+    let resolver = GitHubResolver::new(app.token.clone());
+    let release = generate_release(repo.url()?, commits, &resolver);
 
     println!("{}", to_string_pretty(&release)?);
 
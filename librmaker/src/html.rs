@@ -0,0 +1,96 @@
+//! The *html* module converts the Markdown changelog produced by [`generate_msg`] into
+//! rendered HTML: its reference-style author and commit links are resolved into real
+//! `<a href>` anchors by the Markdown parser, and fenced code blocks are syntax-highlighted
+//! via [syntect].
+//!
+//! [`generate_msg`]: crate::generate_msg
+//! [syntect]: https://github.com/trishume/syntect
+
+use comrak::adapters::SyntaxHighlighterAdapter;
+use comrak::{markdown_to_html_with_plugins, ComrakOptions, ComrakPlugins};
+
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::html::{styled_line_to_highlighted_html, IncludeBackground};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// Highlights fenced code blocks with a default [`SyntaxSet`], falling back to the plain-text
+/// syntax when the fence's language is missing or isn't recognised.
+struct SyntectHighlighter {
+    syntax_set: SyntaxSet,
+    theme: Theme,
+}
+
+impl SyntectHighlighter {
+    fn new() -> Self {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let theme = ThemeSet::load_defaults().themes["InspiredGitHub"].clone();
+
+        Self { syntax_set, theme }
+    }
+}
+
+impl SyntaxHighlighterAdapter for SyntectHighlighter {
+    fn write_highlighted(
+        &self,
+        output: &mut dyn Write,
+        lang: Option<&str>,
+        code: &str,
+    ) -> io::Result<()> {
+        let syntax = lang
+            .and_then(|lang| self.syntax_set.find_syntax_by_token(lang))
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+
+        // Highlight line-by-line and emit only the styled spans: `write_pre_tag`/
+        // `write_code_tag` already wrap the block in `<pre><code>`, so reaching for
+        // `highlighted_html_for_string` here would nest a second, self-contained `<pre>`
+        // inside it.
+        let mut highlighter = HighlightLines::new(syntax, &self.theme);
+
+        for line in LinesWithEndings::from(code) {
+            let regions = highlighter
+                .highlight_line(line, &self.syntax_set)
+                .unwrap_or_default();
+
+            let html = styled_line_to_highlighted_html(&regions, IncludeBackground::No)
+                .unwrap_or_else(|_| line.to_string());
+
+            output.write_all(html.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    fn write_pre_tag(
+        &self,
+        output: &mut dyn Write,
+        _attributes: HashMap<String, String>,
+    ) -> io::Result<()> {
+        output.write_all(b"<pre>")
+    }
+
+    fn write_code_tag(
+        &self,
+        output: &mut dyn Write,
+        _attributes: HashMap<String, String>,
+    ) -> io::Result<()> {
+        output.write_all(b"<code>")
+    }
+}
+
+/// Renders `markdown` (as produced by [`generate_msg`]) to HTML.
+///
+/// [`generate_msg`]: crate::generate_msg
+pub fn render(markdown: &str) -> String {
+    let options = ComrakOptions::default();
+    let highlighter = SyntectHighlighter::new();
+
+    let mut plugins = ComrakPlugins::default();
+    plugins.render.codefence_syntax_highlighter = Some(&highlighter);
+
+    markdown_to_html_with_plugins(markdown, &options, &plugins)
+}
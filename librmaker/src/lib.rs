@@ -4,10 +4,15 @@
 
 #![deny(rust_2018_idioms)]
 
+pub mod html;
+pub mod mail;
+
 use serde::de::{Error as DeError, SeqAccess, Visitor};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use tera::{Context, Tera};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
 use std::fmt;
 use std::marker::PhantomData;
@@ -154,7 +159,7 @@ impl Commit {
 #[derive(Debug, Clone, PartialEq)]
 pub struct CommitConversionError(
     /// The offending string that was passed.
-    pub String
+    pub String,
 );
 
 impl fmt::Display for CommitConversionError {
@@ -220,8 +225,28 @@ pub struct Change(
     pub OneOrMore<Commit>,
 );
 
+impl Change {
+    /// Create a new `Change` from a single author and commit.
+    pub fn new<C, N, A, H>(category: C, name: N, author: A, commit: H) -> Self
+    where
+        C: Into<String>,
+        N: Into<String>,
+        A: Into<String>,
+        H: Into<String>,
+    {
+        let author = Author::try_from(author.into()).unwrap_or_else(|e| match e {});
+
+        Change(
+            category.into(),
+            name.into(),
+            OneOrMore(vec![author]),
+            OneOrMore(vec![Commit::new(commit)]),
+        )
+    }
+}
+
 /// Represents a release of the software from the current snapshot of the repository.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Default, Clone)]
 pub struct Release {
     /// A message describing the release. Placed at the top of the generated output.
     ///
@@ -242,6 +267,16 @@ pub struct Release {
     /// Changes whose purpose was to remove existing functionality.
     #[serde(default)]
     pub removed: Vec<Change>,
+    /// The number of the pull request that introduced a commit, keyed by the commit's hash.
+    ///
+    /// Commits absent from this map are assumed to not have an associated pull request.
+    #[serde(default)]
+    pub prs: HashMap<String, u64>,
+    /// The resolved GitHub display name of an author, keyed by their login.
+    ///
+    /// Authors absent from this map are displayed by their login alone.
+    #[serde(default)]
+    pub author_names: HashMap<String, String>,
 }
 
 impl Release {
@@ -262,105 +297,153 @@ impl Release {
             .collect()
     }
 
-    /// Return all commits of the whole release.
+    /// Return all commits of the whole release, each hash appearing only once even if it
+    /// legitimately belongs to more than one change.
     pub fn get_commits(&self) -> Vec<Commit> {
-        self.iter()
+        let mut seen = HashSet::new();
+        let mut commits = Vec::new();
+
+        for commit in self
+            .iter()
             .flat_map(|Change(_, _, _, OneOrMore(commits))| commits.iter().cloned())
-            .collect()
+        {
+            if seen.insert(commit.clone()) {
+                commits.push(commit);
+            }
+        }
+
+        commits
     }
-}
 
-fn write_separated<T, It>(source: &mut dyn fmt::Write, it: It, sep: &str) -> fmt::Result
-where
-    It: IntoIterator<Item = T>,
-    T: fmt::Display,
-{
-    let it = it.into_iter();
-    let mut first = true;
+    /// Clusters `changes` by their category tag, preserving the order categories first
+    /// appear in and the relative order of changes within a category.
+    fn group_by_category(changes: &[Change]) -> Vec<(&str, Vec<&Change>)> {
+        let mut groups: Vec<(&str, Vec<&Change>)> = Vec::new();
 
-    for elem in it {
-        if !first {
-            source.write_str(sep)?;
-        }
+        for change in changes {
+            let Change(category, ..) = change;
 
-        write!(source, "{}", elem)?;
+            match groups.iter_mut().find(|(c, _)| c == category) {
+                Some((_, group)) => group.push(change),
+                None => groups.push((category, vec![change])),
+            }
+        }
 
-        first = false;
+        groups
     }
-
-    Ok(())
 }
 
-fn write_list(source: &mut dyn fmt::Write, header: &str, changes: &[Change]) -> fmt::Result {
-    if changes.is_empty() {
-        return Ok(());
-    }
-
-    writeln!(source, "{}\n", header)?;
+/// The built-in template: a thank-you list of contributors, `### Added`/`Changed`/`Fixed`/
+/// `Removed` sections whose changes are nested under one bullet per category, and a trailing
+/// block of reference-style links for authors, commits and pull requests. Used whenever
+/// [`generate_msg`] isn't given a custom template.
+pub static DEFAULT_TEMPLATE: &str = include_str!("default.tera");
 
-    for change in changes {
-        let Change(category, name, OneOrMore(authors), OneOrMore(commits)) = change;
+/// A template-friendly view of a [`Commit`], exposing its hash and the pull request (if any)
+/// that introduced it.
+///
+/// [`Commit`]: struct.Commit.html
+#[derive(Serialize)]
+struct CommitContext<'a> {
+    hash: &'a str,
+    pr: Option<u64>,
+}
 
-        assert!(!category.is_empty(), "categores cannot be empty");
+impl<'a> CommitContext<'a> {
+    fn new(commit: &'a Commit, prs: &HashMap<String, u64>) -> Self {
+        CommitContext {
+            hash: commit.hash(),
+            pr: prs.get(commit.hash()).copied(),
+        }
+    }
+}
 
-        write!(source, "- [{}] {} (", category, name)?;
-        write_separated(source, authors, " ")?;
-        write!(source, ") ")?;
+/// A template-friendly view of a [`Change`], exposing its fields by name instead of by
+/// position. Its category is exposed by the enclosing [`ScopeContext`] instead, since changes
+/// are already grouped by it.
+///
+/// [`Change`]: struct.Change.html
+#[derive(Serialize)]
+struct ChangeContext<'a> {
+    name: &'a str,
+    authors: Vec<&'a str>,
+    commits: Vec<CommitContext<'a>>,
+}
 
-        write_separated(source, commits, " ")?;
+impl<'a> ChangeContext<'a> {
+    fn new(change: &'a Change, prs: &HashMap<String, u64>) -> Self {
+        let Change(_, name, OneOrMore(authors), OneOrMore(commits)) = change;
 
-        writeln!(source)?;
+        ChangeContext {
+            name,
+            authors: authors.iter().map(Author::name).collect(),
+            commits: commits.iter().map(|c| CommitContext::new(c, prs)).collect(),
+        }
     }
-
-    writeln!(source)?;
-
-    Ok(())
 }
 
-/// Generate the output message from a [`Release`] by writing to a source implementing
-/// [`std::fmt::Write`]
-///
-/// [`Release`]: struct.Release.html
-/// [`std::fmt::Write`]: std::fmt::Write
-pub fn generate_msg(source: &mut dyn fmt::Write, rel: &Release) -> fmt::Result {
-    if !rel.header.is_empty() {
-        writeln!(source, "{}\n", rel.header)?;
-    }
+/// A template-friendly view of a group of changes sharing the same category tag.
+#[derive(Serialize)]
+struct ScopeContext<'a> {
+    category: &'a str,
+    changes: Vec<ChangeContext<'a>>,
+}
 
-    writeln!(source, "Thanks to the following for their contributions:\n")?;
+/// Groups `changes` by their category tag, converting each into a [`ScopeContext`].
+fn group_changes<'a>(changes: &'a [Change], prs: &HashMap<String, u64>) -> Vec<ScopeContext<'a>> {
+    Release::group_by_category(changes)
+        .into_iter()
+        .map(|(category, changes)| ScopeContext {
+            category,
+            changes: changes.iter().map(|c| ChangeContext::new(c, prs)).collect(),
+        })
+        .collect()
+}
 
+/// Builds the Tera rendering context exposed to templates: the release header and repo URL,
+/// its sorted author list, the flat list of all commits, and each section's changes.
+fn build_context(rel: &Release) -> Context {
     let mut authors = rel.get_authors();
     // Sort authors by their names alphabetically.
     authors.sort_by(|a, b| a.name().to_lowercase().cmp(&b.name().to_lowercase()));
+    let authors: Vec<&str> = authors.iter().map(Author::name).collect();
+
+    let all_commits = rel.get_commits();
+    let commits: Vec<CommitContext<'_>> = all_commits
+        .iter()
+        .map(|c| CommitContext::new(c, &rel.prs))
+        .collect();
+
+    let mut ctx = Context::new();
+    ctx.insert("header", &rel.header);
+    ctx.insert("repo_url", &rel.repo_url);
+    ctx.insert("authors", &authors);
+    ctx.insert("author_names", &rel.author_names);
+    ctx.insert("commits", &commits);
+    ctx.insert("added", &group_changes(&rel.added, &rel.prs));
+    ctx.insert("changed", &group_changes(&rel.changed, &rel.prs));
+    ctx.insert("fixed", &group_changes(&rel.fixed, &rel.prs));
+    ctx.insert("removed", &group_changes(&rel.removed, &rel.prs));
+
+    ctx
+}
 
-    let commits = rel.get_commits();
-
-    for author in &authors {
-        writeln!(source, "- {}", author)?;
-    }
-
-    writeln!(source)?;
-
-    write_list(source, "### Added", &rel.added)?;
-    write_list(source, "### Changed", &rel.changed)?;
-    write_list(source, "### Fixed", &rel.fixed)?;
-    write_list(source, "### Removed", &rel.removed)?;
-
-    for author in authors {
-        writeln!(source, "{}: https://github.com/{}", author, author.name())?;
-    }
-
-    writeln!(source)?;
-
-    for commit in commits {
-        writeln!(
-            source,
-            "{}: {}/commit/{}",
-            commit,
-            rel.repo_url,
-            commit.hash()
-        )?;
-    }
+/// Generate the output message from a [`Release`] by walking `template` (or
+/// [`DEFAULT_TEMPLATE`] when `template` is `None`) and writing the result to a source
+/// implementing [`std::fmt::Write`].
+///
+/// [`Release`]: struct.Release.html
+/// [`std::fmt::Write`]: std::fmt::Write
+pub fn generate_msg(
+    source: &mut dyn fmt::Write,
+    rel: &Release,
+    template: Option<&str>,
+) -> tera::Result<()> {
+    let mut tera = Tera::default();
+    tera.add_raw_template("changelog", template.unwrap_or(DEFAULT_TEMPLATE))?;
+
+    let rendered = tera.render("changelog", &build_context(rel))?;
+    source.write_str(&rendered).map_err(tera::Error::msg)?;
 
     Ok(())
 }
@@ -0,0 +1,39 @@
+//! The *mail* module assembles an RFC 5322 message from a changelog body and hands it to the
+//! local `sendmail` binary for delivery.
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+/// Assembles an RFC 5322 message addressed to `to`, from `from`, with `subject` and `body`,
+/// and pipes it to the local `sendmail` binary.
+pub fn send(from: &str, to: &[String], subject: &str, body: &str) -> io::Result<()> {
+    let message = format!(
+        "From: {}\r\nTo: {}\r\nSubject: {}\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n",
+        from,
+        to.join(", "),
+        subject,
+        body
+    );
+
+    let mut sendmail = Command::new("sendmail")
+        .arg("-t")
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    sendmail
+        .stdin
+        .take()
+        .expect("sendmail's stdin was piped")
+        .write_all(message.as_bytes())?;
+
+    let status = sendmail.wait()?;
+
+    if !status.success() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            "sendmail exited with a non-zero status",
+        ));
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 #![deny(rust_2018_idioms)]
 
-use rmaker::generate_msg;
+use rmaker::{generate_msg, html, mail};
 
 use structopt::StructOpt;
 
@@ -25,6 +25,35 @@ struct App {
     /// Print an explanation of the input's layout and the generated output.
     #[structopt(long)]
     explain: bool,
+    /// Path to a Tera template file to render the changelog with. Falls back to the built-in
+    /// default template, which groups each section's changes by category, when absent.
+    #[structopt(long, parse(from_os_str))]
+    template: Option<PathBuf>,
+    /// The format of the generated changelog.
+    #[structopt(long, default_value = "markdown", possible_values = &["markdown", "html"])]
+    format: String,
+    /// A recipient to mail the generated changelog to, via the local `sendmail` binary. May
+    /// be given multiple times.
+    #[structopt(long)]
+    mail_to: Vec<String>,
+    /// The `From` address of the mail sent to `--mail-to`. Required when `--mail-to` is given.
+    #[structopt(long)]
+    from: Option<String>,
+    /// The `Subject` of the mail sent to `--mail-to`. Defaults to the first non-empty line of
+    /// the release's header.
+    #[structopt(long)]
+    subject: Option<String>,
+    /// Don't print the generated changelog to stdout, whether or not `--mail-to` is given.
+    #[structopt(long)]
+    mail_only: bool,
+}
+
+/// Returns the first non-empty, trimmed line of `s`.
+fn first_non_empty_line(s: &str) -> Option<String> {
+    s.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -54,9 +83,34 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut reader = std::io::BufReader::new(reader);
     let release = serde_json::from_reader(&mut reader)?;
 
-    let mut res = String::new();
-    generate_msg(&mut res, &release)?;
-    println!("{}", res);
+    let template = app.template.map(std::fs::read_to_string).transpose()?;
+
+    let mut body = String::new();
+    generate_msg(&mut body, &release, template.as_deref())?;
+
+    if !app.mail_to.is_empty() {
+        let from = app
+            .from
+            .as_deref()
+            .ok_or("`--from` is required when `--mail-to` is given")?;
+        let subject = app
+            .subject
+            .clone()
+            .or_else(|| first_non_empty_line(&release.header))
+            .unwrap_or_default();
+
+        mail::send(from, &app.mail_to, &subject, &body)?;
+    }
+
+    let mut res = body;
+
+    if app.format == "html" {
+        res = html::render(&res);
+    }
+
+    if !app.mail_only {
+        println!("{}", res);
+    }
 
     Ok(())
 }
@@ -0,0 +1,151 @@
+//! The *conventional* module derives a [`Release`] automatically from a repository's commit
+//! history, by parsing each commit message as a [Conventional Commit].
+//!
+//! [`Release`]: crate::Release
+//! [Conventional Commit]: https://www.conventionalcommits.org/
+
+use ghet::conventional::parse_head;
+use ghet::git::Repository;
+
+use crate::github::{owner_repo_from_url, GitHubResolver};
+use crate::{Author, Change, Commit, OneOrMore, Release};
+
+/// The range of commits to derive a [`Release`] from.
+///
+/// Commits are enumerated from the tip of `branch` down to, but excluding, `since`. When
+/// `since` is `None`, the whole history of `branch` is considered.
+///
+/// `since` must already be a commit id (not an arbitrary ref or tag name): callers resolve it
+/// with the concrete `git2` repository before building a `CommitRange`, since `commit.hash` is
+/// compared against it literally.
+///
+/// [`Release`]: crate::Release
+pub struct CommitRange<'a> {
+    pub branch: &'a str,
+    pub since: Option<&'a str>,
+}
+
+/// The section of a [`Release`] a parsed commit belongs to.
+///
+/// [`Release`]: crate::Release
+enum Kind {
+    Added,
+    Changed,
+    Fixed,
+    Removed,
+}
+
+/// The category tag and fallback used for a commit whose subject doesn't match the
+/// Conventional Commit grammar.
+const FALLBACK_CATEGORY: &str = "misc";
+
+/// Parses the leading `type(scope)!: description` grammar of a Conventional Commit message
+/// (see [`parse_head`]), returning the [`Release`] section it maps to, the category (the
+/// scope, or the commit type when no scope is given) and the description.
+///
+/// A message with no recognisable `type:` prefix falls back to [`Kind::Added`], with
+/// [`FALLBACK_CATEGORY`] as the category and the whole subject as the description. A breaking
+/// change (a `!` before the colon on the subject, or a `BREAKING CHANGE:` footer in the body)
+/// is always routed to [`Kind::Removed`], on top of `revert`, which already maps there.
+///
+/// [`Release`]: crate::Release
+fn parse_subject(message: &str) -> (Kind, String, String) {
+    let fallback = || {
+        let subject = message.lines().next().unwrap_or(message);
+        (
+            Kind::Added,
+            FALLBACK_CATEGORY.to_string(),
+            subject.to_string(),
+        )
+    };
+
+    let head = match parse_head(message) {
+        Some(head) => head,
+        None => return fallback(),
+    };
+
+    let kind = match head.ty {
+        "feat" => Kind::Added,
+        "fix" => Kind::Fixed,
+        "refactor" | "perf" | "style" | "chore" => Kind::Changed,
+        "revert" => Kind::Removed,
+        _ => return fallback(),
+    };
+
+    let kind = if head.breaking { Kind::Removed } else { kind };
+
+    let category = head.scope.unwrap_or_else(|| head.ty.to_string());
+
+    (kind, category, head.description.to_string())
+}
+
+/// Builds a [`Release`] by walking `range` of `repo`'s commits and parsing each summary as a
+/// Conventional Commit. Changes sharing the same category and name are merged, collecting
+/// their authors and commits together.
+///
+/// When `resolver` is given, each commit's author is resolved to a GitHub login instead of
+/// its raw Git `user.name`.
+///
+/// [`Release`]: crate::Release
+pub fn release_from_commits(
+    repo: &impl Repository,
+    range: CommitRange<'_>,
+    resolver: Option<&GitHubResolver>,
+) -> anyhow::Result<Release> {
+    let mut commits = repo.commits(range.branch)?;
+
+    if let Some(since) = range.since {
+        if let Some(pos) = commits.iter().position(|commit| commit.hash == since) {
+            commits.truncate(pos);
+        }
+    }
+
+    let repo_url = repo.url()?;
+    let owner_repo = resolver.and_then(|_| owner_repo_from_url(&repo_url));
+
+    let mut release = Release {
+        repo_url,
+        ..Default::default()
+    };
+
+    for commit in commits {
+        let (kind, category, name) = parse_subject(&commit.message);
+
+        let author_name = match (resolver, &owner_repo) {
+            (Some(resolver), Some((owner, repo_name))) => {
+                resolver.resolve(owner, repo_name, &commit.hash, &commit.author)
+            }
+            _ => commit.author.name.clone(),
+        };
+
+        let author = Author::new(author_name);
+        let commit_ref = Commit::new(commit.hash);
+
+        let bucket = match kind {
+            Kind::Added => &mut release.added,
+            Kind::Changed => &mut release.changed,
+            Kind::Fixed => &mut release.fixed,
+            Kind::Removed => &mut release.removed,
+        };
+
+        match bucket
+            .iter_mut()
+            .find(|Change(c, n, ..)| *c == category && *n == name)
+        {
+            Some(Change(_, _, OneOrMore(authors), OneOrMore(commits))) => {
+                if !authors.contains(&author) {
+                    authors.push(author);
+                }
+                commits.push(commit_ref);
+            }
+            None => bucket.push(Change(
+                category,
+                name,
+                OneOrMore(vec![author]),
+                OneOrMore(vec![commit_ref]),
+            )),
+        }
+    }
+
+    Ok(release)
+}
@@ -0,0 +1,38 @@
+//! The *describe* module finds the most recent tag reachable from a branch tip, the way
+//! `git describe` would, so a changelog can default to "everything since the last release".
+
+use git2::{Repository as Git2Repository, Sort};
+
+use std::collections::HashMap;
+
+use crate::error::Result;
+
+/// Returns the name of the most recent annotated or lightweight tag reachable from `branch`'s
+/// tip in `repo`, or `None` if no tag is reachable.
+pub fn last_tag(repo: &Git2Repository, branch: &str) -> Result<Option<String>> {
+    let reference = repo.find_reference(&format!("refs/remotes/origin/{}", branch))?;
+    let tip = reference.peel_to_commit()?.id();
+
+    // A commit may carry more than one tag; keep all of them so the tie-break below is
+    // deterministic instead of depending on `HashMap`'s iteration order.
+    let mut tags_by_target: HashMap<_, Vec<String>> = HashMap::new();
+
+    for name in repo.tag_names(None)?.iter().flatten() {
+        let target = repo.revparse_single(name)?.peel_to_commit()?.id();
+        tags_by_target.entry(target).or_default().push(name.to_string());
+    }
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(tip)?;
+    revwalk.set_sorting(Sort::TOPOLOGICAL)?;
+
+    for oid in revwalk {
+        if let Some(tags) = tags_by_target.get(&oid?) {
+            // Several tags pointing at the same commit are ordered lexicographically so the
+            // choice is stable across runs.
+            return Ok(tags.iter().max().cloned());
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,74 @@
+//! The *github* module resolves commit authors to their GitHub login by querying the GitHub
+//! REST API, for commit authors whose Git `user.name` doesn't already match their handle.
+
+use ghet::git::User;
+
+pub use ghet::github::owner_repo_from_url;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Resolves Git commit authors to GitHub logins through the [commits API], caching the
+/// mapping per e-mail address so the same author is only queried once per run.
+///
+/// Falls back to the raw commit author name when no token is configured, or when a lookup
+/// fails.
+///
+/// [commits API]: https://docs.github.com/en/rest/commits/commits#get-a-commit
+pub struct GitHubResolver {
+    token: Option<String>,
+    agent: ureq::Agent,
+    cache: RefCell<HashMap<String, String>>,
+}
+
+impl GitHubResolver {
+    /// Creates a resolver. Without a `token`, every lookup is skipped and [`resolve`] always
+    /// returns the raw commit author name.
+    ///
+    /// [`resolve`]: Self::resolve
+    pub fn new(token: Option<String>) -> Self {
+        Self {
+            token,
+            agent: ureq::Agent::new(),
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Resolves the GitHub login of the author of `sha` in `owner/repo`, falling back to
+    /// `user.name` when no token is configured or the lookup fails.
+    pub fn resolve(&self, owner: &str, repo: &str, sha: &str, user: &User) -> String {
+        if let Some(login) = self.cache.borrow().get(&user.email) {
+            return login.clone();
+        }
+
+        let login = self
+            .token
+            .as_deref()
+            .and_then(|token| self.fetch_login(token, owner, repo, sha))
+            .unwrap_or_else(|| user.name.clone());
+
+        self.cache
+            .borrow_mut()
+            .insert(user.email.clone(), login.clone());
+
+        login
+    }
+
+    fn fetch_login(&self, token: &str, owner: &str, repo: &str, sha: &str) -> Option<String> {
+        let url = format!(
+            "https://api.github.com/repos/{}/{}/commits/{}",
+            owner, repo, sha
+        );
+
+        let response = self
+            .agent
+            .get(&url)
+            .set("Authorization", &format!("token {}", token))
+            .set("User-Agent", "release-maker")
+            .call()
+            .ok()?;
+
+        let json: serde_json::Value = response.into_json().ok()?;
+        json["author"]["login"].as_str().map(str::to_string)
+    }
+}
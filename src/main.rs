@@ -1,10 +1,24 @@
 #![deny(rust_2018_idioms)]
 
+mod conventional;
+mod describe;
+mod error;
+mod github;
+mod renderer;
+
 use serde::de::{Error as DeError, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
 use serde::{Deserialize, Deserializer};
 
 use structopt::StructOpt;
 
+use ghet::cache::Cache;
+use ghet::git::{Git, Git2};
+use git2::Repository as Git2Repository;
+
+use conventional::{release_from_commits, CommitRange};
+use github::GitHubResolver;
+
 use std::collections::HashSet;
 use std::convert::TryFrom;
 use std::fmt;
@@ -70,6 +84,18 @@ where
     }
 }
 
+impl<T: Serialize> Serialize for OneOrMore<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+
+        for item in &self.0 {
+            seq.serialize_element(item)?;
+        }
+
+        seq.end()
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 struct Author(String);
 
@@ -100,6 +126,12 @@ impl TryFrom<String> for Author {
     }
 }
 
+impl Serialize for Author {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 struct Commit(String);
 
@@ -139,10 +171,16 @@ impl fmt::Display for Commit {
     }
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl Serialize for Commit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.hash())
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 struct Change(String, String, OneOrMore<Author>, OneOrMore<Commit>);
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 struct Release {
     #[serde(default)]
     header: String,
@@ -181,90 +219,6 @@ impl Release {
     }
 }
 
-fn write_separated<T, It>(f: &mut impl fmt::Write, it: It, sep: &str) -> fmt::Result
-where
-    It: IntoIterator<Item = T>,
-    T: fmt::Display,
-{
-    let it = it.into_iter();
-    let mut first = true;
-
-    for elem in it {
-        if !first {
-            f.write_str(sep)?;
-        }
-
-        write!(f, "{}", elem)?;
-
-        first = false;
-    }
-
-    Ok(())
-}
-
-fn write_list(f: &mut impl fmt::Write, header: &str, changes: &[Change]) -> fmt::Result {
-    if changes.is_empty() {
-        return Ok(());
-    }
-
-    writeln!(f, "{}\n", header)?;
-
-    for change in changes {
-        let Change(category, name, OneOrMore(authors), OneOrMore(commits)) = change;
-
-        assert!(!category.is_empty(), "categores cannot be empty");
-
-        write!(f, "- [{}] {} (", category, name)?;
-        write_separated(f, authors, " ")?;
-        write!(f, ") ")?;
-
-        write_separated(f, commits, " ")?;
-
-        writeln!(f)?;
-    }
-
-    writeln!(f)?;
-
-    Ok(())
-}
-
-fn generate_msg(f: &mut impl fmt::Write, rel: &Release) -> fmt::Result {
-    if !rel.header.is_empty() {
-        writeln!(f, "{}\n", rel.header)?;
-    }
-
-    writeln!(f, "Thanks to the following for their contributions:\n")?;
-
-    let mut authors = rel.get_authors();
-    // Sort authors by their names alphabetically.
-    authors.sort_by(|a, b| a.name().to_lowercase().cmp(&b.name().to_lowercase()));
-
-    let commits = rel.get_commits();
-
-    for author in &authors {
-        writeln!(f, "- {}", author)?;
-    }
-
-    writeln!(f)?;
-
-    write_list(f, "### Added", &rel.added)?;
-    write_list(f, "### Changed", &rel.changed)?;
-    write_list(f, "### Fixed", &rel.fixed)?;
-    write_list(f, "### Removed", &rel.removed)?;
-
-    for author in authors {
-        writeln!(f, "{}: https://github.com/{}", author, author.name())?;
-    }
-
-    writeln!(f)?;
-
-    for commit in commits {
-        writeln!(f, "{}: {}/commit/{}", commit, rel.repo_url, commit.hash())?;
-    }
-
-    Ok(())
-}
-
 #[derive(StructOpt)]
 #[structopt(
     name = "release-maker",
@@ -280,11 +234,74 @@ struct App {
     /// Print an explanation of the input's layout and the generated output.
     #[structopt(long)]
     explain: bool,
+    /// A GitHub personal access token, used to resolve commit authors to their GitHub login
+    /// when deriving a release from git history.
+    #[structopt(long, env = "GITHUB_TOKEN", hide_env_values = true)]
+    token: Option<String>,
+    /// Derive the changelog from a repository's history instead of reading an input file.
+    /// The repository is cloned into, or updated from, the program's cache directory.
+    #[structopt(long)]
+    repo_url: Option<String>,
+    /// The branch to derive the changelog from, when `--repo-url` is given.
+    /// Defaults to `master`.
+    #[structopt(long, default_value = "master")]
+    branch: String,
+    /// Generate the changelog since this ref (exclusive), instead of the most recent tag
+    /// reachable from `--branch`.
+    #[structopt(long)]
+    since: Option<String>,
+    /// Clear the repository cache and exit.
+    #[structopt(long)]
+    clear_cache: bool,
+    /// The format of the generated changelog.
+    #[structopt(
+        long,
+        default_value = "markdown",
+        possible_values = &["markdown", "keep-a-changelog", "json"]
+    )]
+    format: String,
+}
+
+/// Picks the [`Renderer`] named by `--format`.
+///
+/// [`Renderer`]: renderer::Renderer
+fn renderer_for(format: &str) -> Box<dyn renderer::Renderer> {
+    match format {
+        "keep-a-changelog" => Box::new(renderer::KeepAChangelogRenderer),
+        "json" => Box::new(renderer::JsonRenderer),
+        _ => Box::new(renderer::MarkdownRenderer),
+    }
+}
+
+/// Clones `repo_url` into the cache, or opens and fetches it if it was cloned before.
+fn cached_repo(cache: &Cache, repo_url: &str, branch: &str) -> anyhow::Result<Git2Repository> {
+    let path = cache.repository_path_url(repo_url)?;
+
+    if path.exists() {
+        let repo = Git2.open(&path)?;
+
+        // A bare source-only refspec only updates `FETCH_HEAD`, not `refs/remotes/origin/*`,
+        // which is what `commits()` reads; spell out the destination so a cached repo actually
+        // picks up new commits.
+        let refspec = format!("+refs/heads/{0}:refs/remotes/origin/{0}", branch);
+        repo.find_remote("origin")?
+            .fetch(&[refspec], None, None)?;
+
+        Ok(repo)
+    } else {
+        Git2.clone(repo_url, &path)
+    }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> anyhow::Result<()> {
     let app = App::from_args();
 
+    let cache = Cache::new("release-maker")?;
+
+    if app.clear_cache {
+        return cache.clear();
+    }
+
     if app.example {
         print!("{}", EXAMPLE);
     }
@@ -301,16 +318,47 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
-    let reader: Box<dyn std::io::Read> = match app.file {
-        Some(path) => Box::new(File::open(path)?),
-        None => Box::new(std::io::stdin()),
+    let release = if let Some(repo_url) = &app.repo_url {
+        let repo = cached_repo(&cache, repo_url, &app.branch)?;
+        let resolver = GitHubResolver::new(app.token.clone());
+
+        let since = match &app.since {
+            Some(since) => Some(since.clone()),
+            None => describe::last_tag(&repo, &app.branch)?,
+        };
+
+        // `CommitRange::since` is compared against `commit.hash` (a full OID), so a ref or tag
+        // name has to be resolved to the commit it points at before it's usable as a boundary.
+        let since = since
+            .map(|since| -> anyhow::Result<String> {
+                Ok(repo
+                    .revparse_single(&since)?
+                    .peel_to_commit()?
+                    .id()
+                    .to_string())
+            })
+            .transpose()?;
+
+        release_from_commits(
+            &repo,
+            CommitRange {
+                branch: &app.branch,
+                since: since.as_deref(),
+            },
+            Some(&resolver),
+        )?
+    } else {
+        let reader: Box<dyn std::io::Read> = match app.file {
+            Some(path) => Box::new(File::open(path)?),
+            None => Box::new(std::io::stdin()),
+        };
+
+        let mut reader = std::io::BufReader::new(reader);
+        serde_json::from_reader(&mut reader)?
     };
 
-    let mut reader = std::io::BufReader::new(reader);
-    let release = serde_json::from_reader(&mut reader)?;
-
     let mut res = String::new();
-    generate_msg(&mut res, &release)?;
+    renderer_for(&app.format).render(&mut res, &release)?;
     println!("{}", res);
 
     Ok(())
@@ -0,0 +1,205 @@
+//! The *renderer* module turns a [`Release`] into its final output text. [`Renderer`] is the
+//! extension point: the original hand-rolled Markdown layout lives in [`MarkdownRenderer`],
+//! alongside a [Keep a Changelog]-style layout and a JSON round-trip renderer.
+//!
+//! [`Release`]: crate::Release
+//! [Keep a Changelog]: https://keepachangelog.com/
+
+use std::fmt;
+
+use crate::{Change, OneOrMore, Release};
+
+/// Renders a [`Release`] to some output format by writing to a `source` implementing
+/// [`std::fmt::Write`].
+///
+/// [`Release`]: crate::Release
+/// [`std::fmt::Write`]: std::fmt::Write
+pub trait Renderer {
+    fn render(&self, source: &mut dyn fmt::Write, rel: &Release) -> fmt::Result;
+}
+
+fn write_separated<T, It>(source: &mut dyn fmt::Write, it: It, sep: &str) -> fmt::Result
+where
+    It: IntoIterator<Item = T>,
+    T: fmt::Display,
+{
+    let it = it.into_iter();
+    let mut first = true;
+
+    for elem in it {
+        if !first {
+            source.write_str(sep)?;
+        }
+
+        write!(source, "{}", elem)?;
+
+        first = false;
+    }
+
+    Ok(())
+}
+
+fn write_list(source: &mut dyn fmt::Write, header: &str, changes: &[Change]) -> fmt::Result {
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(source, "{}\n", header)?;
+
+    for change in changes {
+        let Change(category, name, OneOrMore(authors), OneOrMore(commits)) = change;
+
+        assert!(!category.is_empty(), "categores cannot be empty");
+
+        write!(source, "- [{}] {} (", category, name)?;
+        write_separated(source, authors, " ")?;
+        write!(source, ") ")?;
+
+        write_separated(source, commits, " ")?;
+
+        writeln!(source)?;
+    }
+
+    writeln!(source)?;
+
+    Ok(())
+}
+
+/// Renders a [`Release`] the way `release-maker` always has: a "thank you" list of
+/// contributors, `### Added`/`Changed`/`Fixed`/`Removed` sections, and a trailing block of
+/// reference-style links for authors and commits.
+///
+/// [`Release`]: crate::Release
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    fn render(&self, source: &mut dyn fmt::Write, rel: &Release) -> fmt::Result {
+        if !rel.header.is_empty() {
+            writeln!(source, "{}\n", rel.header)?;
+        }
+
+        writeln!(source, "Thanks to the following for their contributions:\n")?;
+
+        let mut authors = rel.get_authors();
+        // Sort authors by their names alphabetically.
+        authors.sort_by(|a, b| a.name().to_lowercase().cmp(&b.name().to_lowercase()));
+
+        let commits = rel.get_commits();
+
+        for author in &authors {
+            writeln!(source, "- {}", author)?;
+        }
+
+        writeln!(source)?;
+
+        write_list(source, "### Added", &rel.added)?;
+        write_list(source, "### Changed", &rel.changed)?;
+        write_list(source, "### Fixed", &rel.fixed)?;
+        write_list(source, "### Removed", &rel.removed)?;
+
+        for author in authors {
+            writeln!(source, "{}: https://github.com/{}", author, author.name())?;
+        }
+
+        writeln!(source)?;
+
+        for commit in commits {
+            writeln!(
+                source,
+                "{}: {}/commit/{}",
+                commit,
+                rel.repo_url,
+                commit.hash()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_list_inline(
+    source: &mut dyn fmt::Write,
+    header: &str,
+    changes: &[Change],
+    repo_url: &str,
+) -> fmt::Result {
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(source, "{}\n", header)?;
+
+    for change in changes {
+        let Change(category, name, OneOrMore(authors), OneOrMore(commits)) = change;
+
+        write!(source, "- [{}] {} (", category, name)?;
+
+        let mut first = true;
+        for author in authors {
+            if !first {
+                source.write_str(", ")?;
+            }
+            write!(source, "[@{0}](https://github.com/{0})", author.name())?;
+            first = false;
+        }
+
+        write!(source, ") (")?;
+
+        let mut first = true;
+        for commit in commits {
+            if !first {
+                source.write_str(", ")?;
+            }
+            write!(
+                source,
+                "[{}]({}/commit/{})",
+                &commit.hash()[..7],
+                repo_url,
+                commit.hash()
+            )?;
+            first = false;
+        }
+
+        writeln!(source, ")")?;
+    }
+
+    writeln!(source)?;
+
+    Ok(())
+}
+
+/// Renders a [`Release`] in a [Keep a Changelog]-style layout: grouped sections whose entries
+/// link authors and commits inline, instead of through a trailing reference-link footer.
+///
+/// [`Release`]: crate::Release
+/// [Keep a Changelog]: https://keepachangelog.com/
+pub struct KeepAChangelogRenderer;
+
+impl Renderer for KeepAChangelogRenderer {
+    fn render(&self, source: &mut dyn fmt::Write, rel: &Release) -> fmt::Result {
+        if !rel.header.is_empty() {
+            writeln!(source, "## {}\n", rel.header)?;
+        }
+
+        write_list_inline(source, "### Added", &rel.added, &rel.repo_url)?;
+        write_list_inline(source, "### Changed", &rel.changed, &rel.repo_url)?;
+        write_list_inline(source, "### Fixed", &rel.fixed, &rel.repo_url)?;
+        write_list_inline(source, "### Removed", &rel.removed, &rel.repo_url)?;
+
+        Ok(())
+    }
+}
+
+/// Serializes the normalized [`Release`] back to (pretty-printed) JSON, so downstream tooling
+/// can consume structured output instead of rendered text.
+///
+/// [`Release`]: crate::Release
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, source: &mut dyn fmt::Write, rel: &Release) -> fmt::Result {
+        let json = serde_json::to_string_pretty(rel).map_err(|_| fmt::Error)?;
+
+        source.write_str(&json)
+    }
+}